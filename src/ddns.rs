@@ -0,0 +1,198 @@
+use std::io::Read;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hyper::Client;
+use regex::Regex;
+
+use DoManager;
+use request::DoRequest;
+use request::builder::dns::DnsRecord;
+
+/// Discovers the host's current public address. Implement this to plug in a different
+/// "what is my IP" source (a local interface, a different HTTP service, a STUN lookup, ...)
+/// in place of the bundled `HttpIpResolver`.
+pub trait IpResolver {
+    /// The host's current public IPv4 address, if resolvable.
+    fn resolve_v4(&self) -> Option<Ipv4Addr>;
+    /// The host's current public IPv6 address, if resolvable.
+    fn resolve_v6(&self) -> Option<Ipv6Addr>;
+}
+
+/// Default resolver: fetches a plain-text body from a "what is my IP" HTTP endpoint and
+/// pulls the address out with a regex, since these services differ in surrounding whitespace
+/// but agree on the address itself.
+pub struct HttpIpResolver {
+    pub v4_url: String,
+    pub v6_url: String,
+}
+
+impl HttpIpResolver {
+    /// Uses `icanhazip.com`'s dedicated v4/v6 endpoints, which return nothing but the
+    /// address and a trailing newline.
+    pub fn new() -> HttpIpResolver {
+        HttpIpResolver {
+            v4_url: "https://ipv4.icanhazip.com".to_owned(),
+            v6_url: "https://ipv6.icanhazip.com".to_owned(),
+        }
+    }
+
+    fn fetch(url: &str) -> Option<String> {
+        let client = Client::new();
+        let mut resp = match client.get(url).send() {
+            Ok(r) => r,
+            Err(_) => return None,
+        };
+        let mut body = String::new();
+        match resp.read_to_string(&mut body) {
+            Ok(_) => Some(body),
+            Err(_) => None,
+        }
+    }
+}
+
+impl IpResolver for HttpIpResolver {
+    fn resolve_v4(&self) -> Option<Ipv4Addr> {
+        HttpIpResolver::fetch(&self.v4_url).and_then(|body| extract_v4(&body))
+    }
+
+    fn resolve_v6(&self) -> Option<Ipv6Addr> {
+        HttpIpResolver::fetch(&self.v6_url).and_then(|body| extract_v6(&body))
+    }
+}
+
+fn extract_v4(body: &str) -> Option<Ipv4Addr> {
+    let re = match Regex::new(r"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}") {
+        Ok(re) => re,
+        Err(_) => return None,
+    };
+    re.find(body).and_then(|m| Ipv4Addr::from_str(m.as_str().trim()).ok())
+}
+
+fn extract_v6(body: &str) -> Option<Ipv6Addr> {
+    let re = match Regex::new(r"[0-9a-fA-F]*(?::[0-9a-fA-F]*){2,7}") {
+        Ok(re) => re,
+        Err(_) => return None,
+    };
+    re.find(body).and_then(|m| Ipv6Addr::from_str(m.as_str().trim()).ok())
+}
+
+/// The record(s) a `DdnsUpdater` keeps pointed at this host's public address. Both fields
+/// may be set at once for a dual-stack target.
+pub struct DdnsTarget<'t> {
+    pub domain: &'t str,
+    /// Id of the `A` record to keep updated, if tracking IPv4.
+    pub v4_record_id: Option<String>,
+    /// Id of the `AAAA` record to keep updated, if tracking IPv6.
+    pub v6_record_id: Option<String>,
+}
+
+/// Periodically checks the host's public address and pushes it to the targeted record(s),
+/// but only when the address has actually changed, so a stable connection never triggers
+/// needless DigitalOcean API calls.
+///
+/// # Example
+///
+/// ```no_run
+/// # use doapi::DoManager;
+/// # use doapi::ddns::{DdnsUpdater, DdnsTarget, HttpIpResolver};
+/// # use std::time::Duration;
+/// let domgr = DoManager::with_token("<token>");
+/// let target = DdnsTarget {
+///     domain: "super.com",
+///     v4_record_id: Some("1234".to_owned()),
+///     v6_record_id: None,
+/// };
+/// let mut updater = DdnsUpdater::new(&domgr, HttpIpResolver::new(), target, Duration::from_secs(300));
+/// updater.run(|msg| println!("{}", msg)).ok();
+/// ```
+pub struct DdnsUpdater<'t, R: IpResolver> {
+    domgr: &'t DoManager,
+    resolver: R,
+    target: DdnsTarget<'t>,
+    min_interval: Duration,
+}
+
+impl<'t, R: IpResolver> DdnsUpdater<'t, R> {
+    /// Builds an updater that won't check more often than `min_interval`.
+    pub fn new(domgr: &'t DoManager, resolver: R, target: DdnsTarget<'t>, min_interval: Duration)
+        -> DdnsUpdater<'t, R>
+    {
+        DdnsUpdater {
+            domgr: domgr,
+            resolver: resolver,
+            target: target,
+            min_interval: min_interval,
+        }
+    }
+
+    /// Runs a single check-and-update pass, calling `on_change` with a human-readable
+    /// message for each record actually updated. Returns the number of records updated.
+    ///
+    /// The targeted record's actual current `data` is re-fetched and compared against every
+    /// time, rather than trusting in-process state, so a fresh process doesn't fire a
+    /// redundant update when the record is already correct, and the record drifting back
+    /// out of sync behind our back (edited elsewhere, or recreated) is always caught.
+    pub fn tick<F: FnMut(&str)>(&mut self, mut on_change: F) -> Result<u32, ::Error> {
+        let mut updated = 0;
+
+        if let Some(id) = self.target.v4_record_id.clone() {
+            if let Some(addr) = self.resolver.resolve_v4() {
+                let current = self.current_v4(&id);
+                if current != Some(addr) {
+                    try!(self.push_update(&id, "A", addr.to_string()));
+                    on_change(&format!("A record {} updated to {}", id, addr));
+                    updated += 1;
+                }
+            }
+        }
+
+        if let Some(id) = self.target.v6_record_id.clone() {
+            if let Some(addr) = self.resolver.resolve_v6() {
+                let current = self.current_v6(&id);
+                if current != Some(addr) {
+                    try!(self.push_update(&id, "AAAA", addr.to_string()));
+                    on_change(&format!("AAAA record {} updated to {}", id, addr));
+                    updated += 1;
+                }
+            }
+        }
+
+        Ok(updated)
+    }
+
+    /// Runs `tick` forever, sleeping out whatever's left of `min_interval` between passes.
+    pub fn run<F: FnMut(&str)>(&mut self, mut on_change: F) -> Result<(), ::Error> {
+        loop {
+            let started = Instant::now();
+            try!(self.tick(&mut on_change));
+            let elapsed = started.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+    }
+
+    fn current_v4(&self, record_id: &str) -> Option<Ipv4Addr> {
+        self.current_data(record_id).and_then(|d| Ipv4Addr::from_str(&d).ok())
+    }
+
+    fn current_v6(&self, record_id: &str) -> Option<Ipv6Addr> {
+        self.current_data(record_id).and_then(|d| Ipv6Addr::from_str(&d).ok())
+    }
+
+    fn current_data(&self, record_id: &str) -> Option<String> {
+        match self.domgr.domain(self.target.domain).dns_record(record_id).retrieve() {
+            Ok(record) => record.data,
+            Err(_) => None,
+        }
+    }
+
+    fn push_update(&self, record_id: &str, rec_type: &str, data: String) -> Result<(), ::Error> {
+        let record = DnsRecord::raw(rec_type, None, Some(data), None, None, None);
+        try!(self.domgr.domain(self.target.domain).dns_record(record_id).update_raw(&record).retrieve());
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use std::cell::RefCell;
+
+use request::builder::domain::DomainRequest;
+use response::RateLimit;
+
+/// Entry point for every DigitalOcean API call: holds the API token and tracks the most
+/// recently observed rate-limit window so callers (and `RetryingRequest`) can back off
+/// without re-parsing headers themselves.
+///
+/// # Example
+///
+/// ```no_run
+/// # use doapi::DoManager;
+/// let domgr = DoManager::with_token("<token>");
+/// ```
+pub struct DoManager {
+    pub(crate) token: String,
+    rate_limit: RefCell<Option<RateLimit>>,
+}
+
+impl DoManager {
+    /// Builds a manager authenticated with `token`.
+    pub fn with_token(token: &str) -> DoManager {
+        DoManager {
+            token: token.to_owned(),
+            rate_limit: RefCell::new(None),
+        }
+    }
+
+    /// Starts building a request scoped to the domain `name`.
+    pub fn domain<'t>(&'t self, name: &str) -> DomainRequest<'t> {
+        DomainRequest::new(self, name)
+    }
+
+    /// The `ratelimit-*` headers from the most recent response made through this manager that
+    /// carried all three of them well-formed, or `None` if no such response has come back yet.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        *self.rate_limit.borrow()
+    }
+
+    /// Called by `RequestBuilder::retrieve()` after every response whose `ratelimit-*` headers
+    /// all parsed, to keep `rate_limit()` current.
+    pub(crate) fn record_rate_limit(&self, rate_limit: RateLimit) {
+        *self.rate_limit.borrow_mut() = Some(rate_limit);
+    }
+}
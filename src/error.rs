@@ -0,0 +1,24 @@
+use std::fmt;
+
+/// The error type returned by every `RequestBuilder::retrieve()` call.
+#[derive(Debug)]
+pub enum Error {
+    /// The request couldn't be sent, or the response couldn't be read.
+    Http(String),
+    /// The response body wasn't the JSON shape the caller expected.
+    Json(String),
+    /// DigitalOcean answered `429`. `reset` carries the Unix timestamp the window resets at,
+    /// if at least the `ratelimit-reset` header could be parsed, even when the other two
+    /// ratelimit headers were missing or malformed.
+    RateLimited { reset: Option<u64> },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Http(ref msg) => write!(f, "HTTP error: {}", msg),
+            Error::Json(ref msg) => write!(f, "JSON error: {}", msg),
+            Error::RateLimited { .. } => write!(f, "rate limited"),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate hyper;
+extern crate regex;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+
+#[macro_use]
+mod macros;
+
+pub mod ddns;
+mod domanager;
+mod error;
+pub mod request;
+pub mod response;
+
+pub use domanager::DoManager;
+pub use error::Error;
+pub use request::DoRequest;
@@ -0,0 +1,25 @@
+/// Declares a C-like enum together with a `Display` impl that renders each variant as its
+/// bare name (e.g. `DnsRecType::A` displays as `"A"`), which is what the API expects on the
+/// wire for fields like a record's `type`.
+macro_rules! doapi_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($variant:ident),+
+        }
+    ) => {
+        $(#[$attr])*
+        pub enum $name {
+            $($variant),+
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                let s = match *self {
+                    $($name::$variant => stringify!($variant)),+
+                };
+                write!(f, "{}", s)
+            }
+        }
+    };
+}
@@ -1,12 +1,17 @@
 use std::fmt;
 use std::marker::PhantomData;
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 use hyper::method::Method;
+use serde::{Serialize, Serializer};
 use serde::json;
 
 use response;
 use request::RequestBuilder;
 use request::DoRequest;
+use request::builder::zone;
+use request::builder::sync;
+use DoManager;
 
 /// Lists the types of supported DNS records
 doapi_enum! {
@@ -29,7 +34,7 @@ doapi_enum! {
 // port     nullable number The port that the service is accessible on (for SRV records only. null otherwise).  SRV
 // weight   nullable number The weight of records with the same priority (for SRV records only. null otherwise).    SRV
 /// A struct for creating a DNS Record
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 pub struct DnsRecord {
     /// The type of record (A, AAAA, MX, NS, etc.)
     ///
@@ -88,6 +93,72 @@ impl fmt::Display for DnsRecord {
     }
 }
 
+impl DnsRecord {
+    /// Builds a `DnsRecord` field-by-field, bypassing the validation `RData` gives you.
+    /// Escape hatch for record shapes `RData` doesn't model, or for code (like zone-file
+    /// import or `sync`) that already works in terms of the loose wire representation.
+    pub fn raw(rec_type: &str,
+               name: Option<String>,
+               data: Option<String>,
+               priority: Option<u64>,
+               port: Option<u64>,
+               weight: Option<u64>) -> DnsRecord {
+        DnsRecord {
+            rec_type: rec_type.to_owned(),
+            name: name,
+            priority: priority,
+            port: port,
+            data: data,
+            weight: weight,
+        }
+    }
+}
+
+/// A strongly-typed DNS record value, carrying exactly the fields its `rec_type` needs so
+/// that invalid combinations (an `MX` with no priority, an `A` with a non-IP `data`) can't be
+/// built in the first place.
+///
+/// `A`, `AAAA`, and `NS` have no notion of a host name in this enum and always target the
+/// zone apex when sent to the API (`name: None`); use `DnsRecord::raw` directly if you need a
+/// named `A`/`AAAA`/`NS` record.
+#[derive(Debug, Clone)]
+pub enum RData {
+    A(Ipv4Addr),
+    AAAA(Ipv6Addr),
+    CNAME { name: String, target: String },
+    MX { name: String, priority: u64, exchange: String },
+    SRV { name: String, priority: u64, weight: u64, port: u64, target: String },
+    TXT { name: String, data: String },
+    NS(String),
+}
+
+impl RData {
+    /// Converts into the wire `DnsRecord` DigitalOcean's API expects.
+    pub fn to_dns_record(&self) -> DnsRecord {
+        match *self {
+            RData::A(addr) => DnsRecord::raw("A", None, Some(addr.to_string()), None, None, None),
+            RData::AAAA(addr) => DnsRecord::raw("AAAA", None, Some(addr.to_string()), None, None, None),
+            RData::NS(ref target) => DnsRecord::raw("NS", None, Some(target.clone()), None, None, None),
+            RData::CNAME { ref name, ref target } =>
+                DnsRecord::raw("CNAME", Some(name.clone()), Some(target.clone()), None, None, None),
+            RData::MX { ref name, priority, ref exchange } =>
+                DnsRecord::raw("MX", Some(name.clone()), Some(exchange.clone()), Some(priority), None, None),
+            RData::TXT { ref name, ref data } =>
+                DnsRecord::raw("TXT", Some(name.clone()), Some(data.clone()), None, None, None),
+            RData::SRV { ref name, priority, weight, port, ref target } =>
+                DnsRecord::raw("SRV", Some(name.clone()), Some(target.clone()), Some(priority), Some(port), Some(weight)),
+        }
+    }
+}
+
+impl Serialize for RData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        self.to_dns_record().serialize(serializer)
+    }
+}
+
 /// A type of `RequestBuilder` which allows you make requests related to a single DNS record
 ///
 /// # Example
@@ -102,28 +173,20 @@ pub type DnsRecordRequest<'t> = RequestBuilder<'t, response::DnsRecord>;
 impl<'t> DoRequest<response::DnsRecord> for DnsRecordRequest<'t> {}
 
 impl<'t> DnsRecordsRequest<'t> {
-    /// Returns a `RequestBuilder` for creating a DNS record. 
+    /// Returns a `RequestBuilder` for creating a DNS record.
     ///
     /// **Parameters:**
-    /// `record`: The instance of `DnsRecord` you'd like to create 
+    /// `record`: The `RData` you'd like to create
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use doapi::DoManager;
     /// # use doapi::DoRequest;
-    /// # use doapi::request::DnsRecord;
+    /// # use doapi::request::RData;
+    /// # use std::net::Ipv4Addr;
     /// # let domgr = DoManager::with_token("asfasdfasdf");
-    /// # let record = DnsRecord {
-    /// #   rec_type: "A".to_owned(),
-    /// #   name: None,
-    /// #   priority: None,
-    /// #   port: None,
-    /// #   data: None,
-    /// #   weight: None,
-    /// # };
-    /// // ... domgr set up same as before
-    /// // ... assumes "record" is an instance of doapi::request::DnsRecord
+    /// let record = RData::A(Ipv4Addr::new(10, 0, 0, 1));
     /// match domgr.domain("super.com")
     ///            .dns_records()
     ///            .create(&record)
@@ -132,7 +195,30 @@ impl<'t> DnsRecordsRequest<'t> {
     ///     Err(e)     => println!("Error: {}", e)
     /// }
     /// ```
-    pub fn create(self, record: &DnsRecord) -> DnsRecordRequest<'t> {
+    pub fn create(self, record: &RData) -> DnsRecordRequest<'t> {
+        self.create_raw(&record.to_dns_record())
+    }
+
+    /// Returns a `RequestBuilder` for creating a DNS record from a raw `DnsRecord`, bypassing
+    /// `RData`'s validation. Escape hatch for record shapes `RData` doesn't model.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::DnsRecord;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// let record = DnsRecord::raw("A", Some("www".to_owned()), Some("10.0.0.1".to_owned()), None, None, None);
+    /// match domgr.domain("super.com")
+    ///            .dns_records()
+    ///            .create_raw(&record)
+    ///            .retrieve() {
+    ///     Ok(dns_rec) => println!("Record: {}", dns_rec),
+    ///     Err(e)     => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn create_raw(self, record: &DnsRecord) -> DnsRecordRequest<'t> {
         // POST: "https://api.digitalocean.com/v2/domains/$DOMAIN/records"
         // body:
         //      "type" : "MX"            All records
@@ -166,29 +252,21 @@ impl<'t> DnsRecordsRequest<'t> {
 /// ```
 pub type DnsRecordsRequest<'t> = RequestBuilder<'t, response::DnsRecords>;
 
-impl<'t> DnsRecordsRequest<'t> {
-    /// Returns a `RequestBuilder` for updating an existing DNS record. 
+impl<'t> DnsRecordRequest<'t> {
+    /// Returns a `RequestBuilder` for updating an existing DNS record.
     ///
     /// **Parameters:**
-    /// `record`: The new instance of `DnsRecord` you'd like to update to
+    /// `record`: The `RData` you'd like to update to
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use doapi::DoManager;
     /// # use doapi::DoRequest;
-    /// # use doapi::request::DnsRecord;
+    /// # use doapi::request::RData;
+    /// # use std::net::Ipv4Addr;
     /// # let domgr = DoManager::with_token("asfasdfasdf");
-    /// # let record = DnsRecord {
-    /// #   rec_type: "A".to_owned(),
-    /// #   name: None,
-    /// #   priority: None,
-    /// #   port: None,
-    /// #   data: None,
-    /// #   weight: None,
-    /// # };
-    /// // ... domgr set up same as before
-    /// // ... assumes "record" is an instance of doapi::request::DnsRecord
+    /// let record = RData::A(Ipv4Addr::new(10, 0, 0, 2));
     /// match domgr.domain("super.com")
     ///            .dns_record("1234")
     ///            .update(&record)
@@ -197,7 +275,30 @@ impl<'t> DnsRecordsRequest<'t> {
     ///     Err(e)     => println!("Error: {}", e)
     /// }
     /// ```
-    pub fn update(self, record: &DnsRecord) -> DnsRecordRequest<'t> {
+    pub fn update(self, record: &RData) -> DnsRecordRequest<'t> {
+        self.update_raw(&record.to_dns_record())
+    }
+
+    /// Returns a `RequestBuilder` for updating an existing DNS record from a raw `DnsRecord`,
+    /// bypassing `RData`'s validation. Escape hatch for record shapes `RData` doesn't model.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::DnsRecord;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// let record = DnsRecord::raw("A", Some("www".to_owned()), Some("10.0.0.2".to_owned()), None, None, None);
+    /// match domgr.domain("super.com")
+    ///            .dns_record("1234")
+    ///            .update_raw(&record)
+    ///            .retrieve() {
+    ///     Ok(dns_rec) => println!("Record: {}", dns_rec),
+    ///     Err(e)     => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn update_raw(self, record: &DnsRecord) -> DnsRecordRequest<'t> {
         // PUT: "https://api.digitalocean.com/v2/domains/$DOMAIN/records/$ID"
         // body:
         //      "type" : "MX"           All records
@@ -216,7 +317,7 @@ impl<'t> DnsRecordsRequest<'t> {
         }
     }
 
-    /// Returns a `RequestBuilder` for deleting an existing DNS record. 
+    /// Returns a `RequestBuilder` for deleting an existing DNS record.
     ///
     /// # Example
     ///
@@ -244,3 +345,86 @@ impl<'t> DnsRecordsRequest<'t> {
         }
     }
 }
+
+impl<'t> DnsRecordsRequest<'t> {
+    /// Parses a BIND/Knot-style master zone file into the `DnsRecord`s it describes. This
+    /// doesn't talk to the API itself; feed each returned record to `create_raw(...)` to
+    /// populate the domain.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// let zone_text = "$ORIGIN super.com.\n@ IN A 10.0.0.1\nwww IN CNAME @\n";
+    /// for record in domgr.domain("super.com").dns_records().import_zone(zone_text) {
+    ///     domgr.domain("super.com").dns_records().create_raw(&record).retrieve().ok();
+    /// }
+    /// ```
+    pub fn import_zone(&self, zone_text: &str) -> Vec<DnsRecord> {
+        zone::parse_zone(zone_text)
+    }
+
+    /// Retrieves every record on the domain and renders it back into zone-file text.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.domain("super.com").dns_records().export_zone("super.com") {
+    ///     Ok(zone_text) => println!("{}", zone_text),
+    ///     Err(e)        => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn export_zone(self, domain: &str) -> Result<String, ::Error> {
+        let records = try!(self.retrieve());
+        Ok(zone::render_zone(domain, &records.dns_records))
+    }
+
+    /// Reconciles `desired` against whatever currently exists on the domain, issuing the
+    /// minimal set of `create`/`update`/`delete` calls needed to make them match.
+    ///
+    /// Records sharing a `(name, type)` key (round-robin `A` sets, multi-host `MX`/`SRV`
+    /// groups) are matched by `data` value before falling back to positional pairing. The
+    /// apex `NS` and `SOA` records are never deleted, even if `desired` omits them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::DnsRecord;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// let desired = vec![DnsRecord {
+    ///     rec_type: "A".to_owned(),
+    ///     name: Some("www".to_owned()),
+    ///     data: Some("10.0.0.1".to_owned()),
+    ///     priority: None, port: None, weight: None,
+    /// }];
+    /// match domgr.domain("super.com").dns_records().sync(&domgr, "super.com", &desired) {
+    ///     Ok(summary) => println!("{:?}", summary),
+    ///     Err(e)      => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn sync(self, domgr: &DoManager, domain_name: &str, desired: &[DnsRecord])
+        -> Result<sync::SyncSummary, ::Error>
+    {
+        let current = try!(self.retrieve()).dns_records;
+        let plan = sync::plan(&current, desired);
+
+        for record in &plan.to_create {
+            try!(domgr.domain(domain_name).dns_records().create_raw(record).retrieve());
+        }
+        for (id, record) in plan.to_update {
+            try!(domgr.domain(domain_name).dns_record(&id.to_string()).update_raw(&record).retrieve());
+        }
+        for id in plan.to_delete {
+            try!(domgr.domain(domain_name).dns_record(&id.to_string()).delete().retrieve());
+        }
+
+        Ok(plan.summary)
+    }
+}
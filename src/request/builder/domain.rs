@@ -0,0 +1,51 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+
+use request::RequestBuilder;
+use request::builder::dns::{DnsRecordRequest, DnsRecordsRequest};
+use domanager::DoManager;
+
+/// A type of `RequestBuilder` which allows you to make requests related to a single domain.
+///
+/// # Example
+///
+/// ```no_run
+/// # use doapi::DoManager;
+/// let domgr = DoManager::with_token("<token>");
+/// let domain_request = domgr.domain("super.com");
+/// ```
+pub struct DomainRequest<'t> {
+    auth: &'t DoManager,
+    name: String,
+}
+
+impl<'t> DomainRequest<'t> {
+    pub fn new(auth: &'t DoManager, name: &str) -> DomainRequest<'t> {
+        DomainRequest { auth: auth, name: name.to_owned() }
+    }
+
+    /// Returns a `RequestBuilder` for the records belonging to this domain as a whole.
+    pub fn dns_records(&self) -> DnsRecordsRequest<'t> {
+        RequestBuilder {
+            method: Method::Get,
+            auth: self.auth,
+            url: format!("https://api.digitalocean.com/v2/domains/{}/records", self.name),
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+
+    /// Returns a `RequestBuilder` scoped to a single record on this domain. Unlike
+    /// `dns_records()`, a bare `retrieve()` here decodes the unwrapped `response::DnsRecord`
+    /// object the by-id endpoint actually returns, not a `{"dns_records": [...]}` list.
+    pub fn dns_record(&self, id: &str) -> DnsRecordRequest<'t> {
+        RequestBuilder {
+            method: Method::Get,
+            auth: self.auth,
+            url: format!("https://api.digitalocean.com/v2/domains/{}/records/{}", self.name, id),
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod dns;
+pub mod domain;
+pub mod sync;
+pub mod zone;
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use request::builder::dns::DnsRecord;
+use response;
+
+/// `(name, record type)` — the unit DigitalOcean lets you target more than once, e.g. a
+/// round-robin set of `A` records or several `MX` hosts at the same name.
+type RecordKey = (String, String);
+
+/// What `sync` did (or is about to do) to reconcile a domain's records with a desired state.
+#[derive(Debug, Default)]
+pub struct SyncSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub deleted: usize,
+    pub unchanged: usize,
+}
+
+/// The minimal set of create/update/delete actions needed to turn `current` into `desired`.
+pub struct Plan {
+    pub to_create: Vec<DnsRecord>,
+    pub to_update: Vec<(u64, DnsRecord)>,
+    pub to_delete: Vec<u64>,
+    pub summary: SyncSummary,
+}
+
+/// Diffs `current` (as fetched from the API) against `desired` (the declared state) and
+/// returns the minimal set of actions to reconcile them.
+///
+/// Records are grouped by `(name, type)`; within a group, desired records are matched to
+/// current ones by `data` value first, falling back to positional pairing for whatever's
+/// left (covers round-robin `A` sets and multi-host `MX`/`SRV` groups). The domain's apex
+/// `NS` and `SOA` records are never scheduled for deletion, even when `desired` omits them.
+pub fn plan(current: &[response::DnsRecord], desired: &[DnsRecord]) -> Plan {
+    let mut current_groups: HashMap<RecordKey, Vec<&response::DnsRecord>> = HashMap::new();
+    for r in current {
+        current_groups.entry(key(&r.name, &r.rec_type)).or_insert_with(Vec::new).push(r);
+    }
+
+    let mut desired_groups: HashMap<RecordKey, Vec<&DnsRecord>> = HashMap::new();
+    for r in desired {
+        desired_groups.entry(key(&r.name, &r.rec_type)).or_insert_with(Vec::new).push(r);
+    }
+
+    let mut all_keys: Vec<RecordKey> = current_groups.keys().cloned().collect();
+    for k in desired_groups.keys() {
+        if !all_keys.contains(k) {
+            all_keys.push(k.clone());
+        }
+    }
+
+    let mut to_create = Vec::new();
+    let mut to_update = Vec::new();
+    let mut to_delete = Vec::new();
+    let mut summary = SyncSummary::default();
+
+    for k in all_keys {
+        let is_apex_protected = k.0 == "@" && (k.1 == "NS" || k.1 == "SOA");
+        let mut unmatched_current: Vec<&response::DnsRecord> =
+            current_groups.get(&k).cloned().unwrap_or_default();
+        let desireds: Vec<&DnsRecord> = desired_groups.get(&k).cloned().unwrap_or_default();
+        let mut unmatched_desired = Vec::new();
+
+        // First pass: match by `data` value within the group.
+        for d in desireds {
+            match unmatched_current.iter().position(|c| c.data == d.data) {
+                Some(pos) => {
+                    let c = unmatched_current.remove(pos);
+                    record_match(c, d, &mut to_update, &mut summary);
+                }
+                None => unmatched_desired.push(d),
+            }
+        }
+
+        // Second pass: whatever's left over is paired positionally.
+        for d in unmatched_desired {
+            if unmatched_current.is_empty() {
+                to_create.push(d.clone());
+                summary.created += 1;
+            } else {
+                let c = unmatched_current.remove(0);
+                record_match(c, d, &mut to_update, &mut summary);
+            }
+        }
+
+        if !is_apex_protected {
+            for c in unmatched_current {
+                to_delete.push(c.id);
+                summary.deleted += 1;
+            }
+        }
+    }
+
+    Plan { to_create: to_create, to_update: to_update, to_delete: to_delete, summary: summary }
+}
+
+fn record_match(current: &response::DnsRecord,
+                 desired: &DnsRecord,
+                 to_update: &mut Vec<(u64, DnsRecord)>,
+                 summary: &mut SyncSummary) {
+    if values_equal(current, desired) {
+        summary.unchanged += 1;
+    } else {
+        to_update.push((current.id, desired.clone()));
+        summary.updated += 1;
+    }
+}
+
+fn values_equal(current: &response::DnsRecord, desired: &DnsRecord) -> bool {
+    current.data == desired.data &&
+        current.priority == desired.priority &&
+        current.port == desired.port &&
+        current.weight == desired.weight
+}
+
+fn key(name: &Option<String>, rec_type: &str) -> RecordKey {
+    (name.clone().unwrap_or_else(|| "@".to_owned()), rec_type.to_uppercase())
+}
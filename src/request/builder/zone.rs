@@ -0,0 +1,176 @@
+use request::builder::dns::DnsRecord;
+use response;
+
+/// Parses a BIND/Knot-style master zone file (RFC 1035 presentation format) into the
+/// `DnsRecord`s it describes.
+///
+/// Understands `$ORIGIN`, owner-name inheritance (a blank owner field reuses the previous
+/// record's name), the `@` origin shorthand, and parenthesized multi-line records. `$TTL` is
+/// recognised and skipped, since DigitalOcean manages TTLs itself rather than accepting one
+/// per record. Record types `DnsRecType` doesn't model (e.g. `SOA`) are skipped.
+pub fn parse_zone(zone: &str) -> Vec<DnsRecord> {
+    let mut origin = "@".to_owned();
+    let mut last_name: Option<String> = None;
+    let mut records = Vec::new();
+
+    for raw_line in join_parens(zone) {
+        let no_comment = strip_comment(&raw_line);
+        if no_comment.trim().is_empty() {
+            continue;
+        }
+        let owner_omitted = no_comment.chars().next().map_or(false, |c| c.is_whitespace());
+        let line = no_comment.trim();
+
+        if line.starts_with("$ORIGIN") {
+            if let Some(o) = line.split_whitespace().nth(1) {
+                origin = o.to_owned();
+            }
+            continue;
+        }
+        if line.starts_with("$TTL") {
+            continue;
+        }
+
+        let mut fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.is_empty() {
+            continue;
+        }
+
+        let owner = if owner_omitted {
+            last_name.clone().unwrap_or_else(|| origin.clone())
+        } else {
+            fields.remove(0).to_owned()
+        };
+        last_name = Some(owner.clone());
+
+        // Skip an optional TTL and/or class (IN) field ahead of the record type.
+        while fields.len() > 1 &&
+              (fields[0].chars().all(|c| c.is_ascii_digit()) || fields[0].eq_ignore_ascii_case("IN")) {
+            fields.remove(0);
+        }
+        if fields.is_empty() {
+            continue;
+        }
+
+        let rec_type = fields.remove(0).to_uppercase();
+        let name = Some(relativize(&owner, &origin));
+
+        let record = match rec_type.as_str() {
+            "A" | "AAAA" | "CNAME" | "NS" | "TXT" => DnsRecord {
+                rec_type: rec_type,
+                name: name,
+                data: fields.get(0).map(|s| s.trim_matches('"').to_owned()),
+                priority: None,
+                port: None,
+                weight: None,
+            },
+            "MX" => DnsRecord {
+                rec_type: rec_type,
+                name: name,
+                priority: fields.get(0).and_then(|s| s.parse().ok()),
+                data: fields.get(1).map(|s| s.to_owned()),
+                port: None,
+                weight: None,
+            },
+            "SRV" => DnsRecord {
+                rec_type: rec_type,
+                name: name,
+                priority: fields.get(0).and_then(|s| s.parse().ok()),
+                weight: fields.get(1).and_then(|s| s.parse().ok()),
+                port: fields.get(2).and_then(|s| s.parse().ok()),
+                data: fields.get(3).map(|s| s.to_owned()),
+            },
+            _ => continue,
+        };
+
+        records.push(record);
+    }
+
+    records
+}
+
+/// Renders `records` back into master zone-file text relative to `$ORIGIN domain.`.
+///
+/// Record types `DnsRecType` doesn't model (e.g. the apex `SOA` record DigitalOcean always
+/// includes) are skipped, mirroring `parse_zone`, rather than emitted with fields they need
+/// but this crate has no data for.
+pub fn render_zone(domain: &str, records: &[response::DnsRecord]) -> String {
+    let mut out = format!("$ORIGIN {}.\n", domain);
+
+    for r in records {
+        let name = r.name.clone().unwrap_or_else(|| "@".to_owned());
+        match r.rec_type.as_str() {
+            "MX" => out.push_str(&format!("{} IN MX {} {}\n",
+                name, r.priority.unwrap_or(0), r.data.clone().unwrap_or_default())),
+            "SRV" => out.push_str(&format!("{} IN SRV {} {} {} {}\n",
+                name, r.priority.unwrap_or(0), r.weight.unwrap_or(0),
+                r.port.unwrap_or(0), r.data.clone().unwrap_or_default())),
+            "TXT" => out.push_str(&format!("{} IN TXT \"{}\"\n", name, r.data.clone().unwrap_or_default())),
+            "A" | "AAAA" | "CNAME" | "NS" =>
+                out.push_str(&format!("{} IN {} {}\n", name, r.rec_type, r.data.clone().unwrap_or_default())),
+            _ => continue,
+        }
+    }
+
+    out
+}
+
+/// Joins `(` ... `)` continued records into single logical lines, the way a zone-file parser
+/// has to before it can safely split on whitespace. Tracks a running paren depth across the
+/// accumulated group rather than per physical line, so a record spanning more than two lines
+/// (a multi-string TXT/SPF record, say) is joined in full instead of being cut short after the
+/// first continuation line.
+fn join_parens(zone: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut pending: Option<String> = None;
+    let mut depth: i64 = 0;
+
+    for line in zone.lines() {
+        depth += line.matches('(').count() as i64;
+        depth -= line.matches(')').count() as i64;
+
+        let joined = match pending.take() {
+            Some(mut acc) => { acc.push(' '); acc.push_str(line); acc }
+            None => line.to_owned(),
+        };
+
+        if depth > 0 {
+            pending = Some(joined.replace('(', " "));
+        } else {
+            depth = 0; // a stray ')' shouldn't push us into negative depth
+            out.push(joined.replace('(', " ").replace(')', " "));
+        }
+    }
+
+    if let Some(acc) = pending {
+        out.push(acc);
+    }
+
+    out
+}
+
+/// Strips a `;` comment, respecting neither escapes nor quoting (zone files rarely need
+/// either here, since the comment marker isn't legal inside the file's own tokens).
+fn strip_comment(line: &str) -> String {
+    match line.find(';') {
+        Some(i) => line[..i].to_owned(),
+        None => line.to_owned(),
+    }
+}
+
+/// Turns an owner name into the form the DigitalOcean API expects: `@` for the zone apex,
+/// otherwise the name relative to `origin` with any trailing dot removed.
+fn relativize(owner: &str, origin: &str) -> String {
+    let trimmed = owner.trim_end_matches('.');
+    let origin = origin.trim_end_matches('.');
+
+    let suffix = format!(".{}", origin);
+
+    if trimmed == "@" || trimmed == origin {
+        "@".to_owned()
+    } else if trimmed.ends_with(&suffix) {
+        trimmed[..trimmed.len() - suffix.len()].to_owned()
+    } else {
+        trimmed.to_owned()
+    }
+}
@@ -0,0 +1,83 @@
+use std::io::Read;
+use std::marker::PhantomData;
+
+use hyper::Client;
+use hyper::header::{Authorization, Bearer, ContentType, Headers};
+use hyper::method::Method;
+use serde::Deserialize;
+use serde::json;
+
+pub mod builder;
+mod ratelimit;
+
+pub use self::builder::dns::{DnsRecord, DnsRecType, RData};
+pub use self::builder::domain::DomainRequest;
+pub use self::ratelimit::{RetryPolicy, RetryingRequest};
+
+use domanager::DoManager;
+use error::Error;
+use response::RateLimit;
+
+/// Marker trait tying a `RequestBuilder<'t, T>` to the response type `T` it deserializes
+/// into; the actual work happens in `RequestBuilder::retrieve()`.
+pub trait DoRequest<T> {}
+
+/// Describes a single DigitalOcean API call: method, URL, optional JSON body, and the
+/// `DoManager` whose token authenticates it and whose rate-limit cache gets updated once the
+/// response comes back.
+pub struct RequestBuilder<'t, T> {
+    pub method: Method,
+    pub auth: &'t DoManager,
+    pub url: String,
+    pub resp_t: PhantomData<T>,
+    pub body: Option<String>,
+}
+
+impl<'t, T> RequestBuilder<'t, T>
+    where T: Deserialize
+{
+    /// Issues the request and deserializes the JSON response into `T`.
+    ///
+    /// The `ratelimit-*` headers are parsed off of *every* response (success or failure) and,
+    /// when all three are present and well-formed, recorded on `self.auth` via
+    /// `DoManager::record_rate_limit`; a response without them leaves the previously recorded
+    /// window in place. Any `429`, well-formed ratelimit headers or not, is
+    /// surfaced as `Error::RateLimited` rather than falling through to a JSON-decode error
+    /// that could never match `T`.
+    pub fn retrieve(&self) -> Result<T, Error> {
+        let client = Client::new();
+        let mut req = match self.method {
+            Method::Post => client.post(&self.url),
+            Method::Put => client.put(&self.url),
+            Method::Delete => client.delete(&self.url),
+            _ => client.get(&self.url),
+        };
+
+        let mut headers = Headers::new();
+        headers.set(Authorization(Bearer { token: self.auth.token.clone() }));
+        if self.body.is_some() {
+            headers.set(ContentType::json());
+        }
+        req = req.headers(headers);
+        if let Some(ref body) = self.body {
+            req = req.body(body.as_str());
+        }
+
+        let mut resp = try!(req.send().map_err(|e| Error::Http(format!("{}", e))));
+
+        let rate_limit = RateLimit::from_headers(&resp.headers);
+        if let Some(rl) = rate_limit {
+            self.auth.record_rate_limit(rl);
+        }
+        if resp.status.as_u16() == 429 {
+            let reset = rate_limit.map(|rl| rl.reset)
+                .or_else(|| RateLimit::reset_from_headers(&resp.headers));
+            return Err(Error::RateLimited { reset: reset });
+        }
+
+        let mut body = String::new();
+        try!(resp.read_to_string(&mut body).map_err(|e| Error::Http(format!("{}", e))));
+
+        json::from_str(&body).map_err(|e| Error::Json(format!("{}", e)))
+    }
+}
@@ -0,0 +1,80 @@
+use std::thread;
+use std::time::Duration;
+
+use error::Error;
+use request::RequestBuilder;
+use response::RateLimit;
+use serde::Deserialize;
+
+/// Controls how many times a request is retried once DigitalOcean
+/// reports its quota exhausted.
+///
+/// # Example
+///
+/// ```no_run
+/// # use doapi::request::RetryPolicy;
+/// let policy = RetryPolicy::new(5);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after a rate-limited response before giving up.
+    pub max_retries: u32,
+}
+
+impl RetryPolicy {
+    /// Builds a policy that retries up to `max_retries` times.
+    pub fn new(max_retries: u32) -> RetryPolicy {
+        RetryPolicy { max_retries: max_retries }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three retries, matching the default most DigitalOcean windows reset well within.
+    fn default() -> RetryPolicy {
+        RetryPolicy::new(3)
+    }
+}
+
+/// An opt-in extension of `RequestBuilder::retrieve()` that waits out DigitalOcean's rate
+/// limit instead of failing when the current window is exhausted.
+///
+/// The originating `DoManager` (`self.auth`) is consulted — and kept up to date by
+/// `retrieve()` itself, which records the `ratelimit-*` headers off of every response that
+/// carries all three — for the most recently observed `RateLimit`. A `429` waits until its own
+/// `ratelimit-reset` if that header parsed, otherwise until the cached window's reset, and
+/// sleeps a flat minute only when neither is known.
+pub trait RetryingRequest<T> {
+    /// Same as `retrieve()`, but sleeps out the window and retries (up to
+    /// `policy.max_retries` times) whenever the quota is exhausted before the request is
+    /// issued, or comes back exhausted (`429`) after it is.
+    fn retrieve_with_backoff(&self, policy: RetryPolicy) -> Result<T, Error>;
+}
+
+impl<'t, T> RetryingRequest<T> for RequestBuilder<'t, T>
+    where T: Deserialize
+{
+    fn retrieve_with_backoff(&self, policy: RetryPolicy) -> Result<T, Error> {
+        let mut attempts = 0;
+        loop {
+            if let Some(rl) = self.auth.rate_limit() {
+                if rl.is_exhausted() && attempts < policy.max_retries {
+                    thread::sleep(Duration::from_secs(rl.seconds_until_reset()));
+                    attempts += 1;
+                }
+            }
+
+            match self.retrieve() {
+                Err(Error::RateLimited { reset }) if attempts < policy.max_retries => {
+                    // Prefer the reset the 429 itself carried; fall back to whatever's cached
+                    // on `self.auth`, then to a flat one-minute cooldown if neither is known.
+                    let wait = reset.map(RateLimit::seconds_until)
+                        .or_else(|| self.auth.rate_limit().map(|rl| rl.seconds_until_reset()))
+                        .unwrap_or(60);
+                    thread::sleep(Duration::from_secs(wait));
+                    attempts += 1;
+                }
+                other => return other,
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+
+/// A DNS record as returned by the API: the same fields a request-side `DnsRecord` carries,
+/// plus the `id` DigitalOcean assigned it.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DnsRecord {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub rec_type: String,
+    pub name: Option<String>,
+    pub data: Option<String>,
+    pub priority: Option<u64>,
+    pub port: Option<u64>,
+    pub weight: Option<u64>,
+}
+
+impl fmt::Display for DnsRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+            "Id: {}\n\
+             Record Type: {}\n\
+             Name: {}\n\
+             Data: {}\n\
+             Priority: {}\n\
+             Port: {}\n\
+             Weight: {}\n",
+             self.id,
+             self.rec_type,
+             self.name.clone().unwrap_or_else(|| "None".to_owned()),
+             self.data.clone().unwrap_or_else(|| "None".to_owned()),
+             self.priority.map(|p| p.to_string()).unwrap_or_else(|| "None".to_owned()),
+             self.port.map(|p| p.to_string()).unwrap_or_else(|| "None".to_owned()),
+             self.weight.map(|w| w.to_string()).unwrap_or_else(|| "None".to_owned())
+        )
+    }
+}
+
+/// The `GET .../records` response: every record on the domain.
+#[derive(Deserialize, Debug)]
+pub struct DnsRecords {
+    pub dns_records: Vec<DnsRecord>,
+}
+
+/// A response that carries nothing but headers and a status code (e.g. from `delete`).
+#[derive(Debug)]
+pub struct HeaderOnly;
+
+impl ::serde::Deserialize for HeaderOnly {
+    fn deserialize<D>(_deserializer: D) -> Result<HeaderOnly, D::Error>
+        where D: ::serde::Deserializer
+    {
+        Ok(HeaderOnly)
+    }
+}
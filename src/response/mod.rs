@@ -0,0 +1,7 @@
+mod account;
+mod dns;
+mod ratelimit;
+
+pub use self::account::Account;
+pub use self::dns::{DnsRecord, DnsRecords, HeaderOnly};
+pub use self::ratelimit::RateLimit;
@@ -0,0 +1,77 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hyper::header::Headers;
+
+header! { (RatelimitLimit, "ratelimit-limit") => [u64] }
+header! { (RatelimitRemaining, "ratelimit-remaining") => [u64] }
+header! { (RatelimitReset, "ratelimit-reset") => [u64] }
+
+/// A snapshot of the `ratelimit-*` headers DigitalOcean attaches to every API response.
+///
+/// **NOTE:** `reset` is a Unix timestamp (seconds), not a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Maximum number of requests allowed in the current window.
+    pub limit: u64,
+    /// Requests left in the current window.
+    pub remaining: u64,
+    /// Unix timestamp at which `remaining` resets back to `limit`.
+    pub reset: u64,
+}
+
+impl RateLimit {
+    /// Parses the three `ratelimit-*` headers off a response. Returns `None` if any of them
+    /// is absent or fails to parse, since a partial reading isn't trustworthy enough to act on.
+    pub fn from_headers(headers: &Headers) -> Option<RateLimit> {
+        let limit = match headers.get::<RatelimitLimit>() {
+            Some(h) => h.0,
+            None => return None,
+        };
+        let remaining = match headers.get::<RatelimitRemaining>() {
+            Some(h) => h.0,
+            None => return None,
+        };
+        let reset = match headers.get::<RatelimitReset>() {
+            Some(h) => h.0,
+            None => return None,
+        };
+
+        Some(RateLimit { limit: limit, remaining: remaining, reset: reset })
+    }
+
+    /// Parses just the `ratelimit-reset` header. Useful when `from_headers` came back `None`
+    /// (one of `limit`/`remaining` was missing or malformed) but there's still enough here to
+    /// compute a precise retry delay instead of falling back to a flat guess.
+    pub fn reset_from_headers(headers: &Headers) -> Option<u64> {
+        headers.get::<RatelimitReset>().map(|h| h.0)
+    }
+
+    /// Whole seconds remaining until this window resets, relative to now. `0` if `reset` is
+    /// already in the past.
+    pub fn seconds_until_reset(&self) -> u64 {
+        Self::seconds_until(self.reset)
+    }
+
+    /// Whole seconds remaining until the Unix timestamp `reset`, relative to now. `0` if it's
+    /// already in the past. Shared by `seconds_until_reset` and by callers that only have a
+    /// bare `reset` timestamp (e.g. from `Error::RateLimited`), not a full `RateLimit`.
+    pub(crate) fn seconds_until(reset: u64) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        reset.saturating_sub(now)
+    }
+
+    /// `true` once the current window has no requests left.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+impl fmt::Display for RateLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{} remaining, resets in {}s", self.remaining, self.limit, self.seconds_until_reset())
+    }
+}